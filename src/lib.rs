@@ -0,0 +1,323 @@
+use std::io::Write;
+
+/// Run `$body` over every item of an iterator, falling through to `$else`
+/// only when the iterator is empty — a Python-style `for … else`.
+///
+/// The macro is an expression: `$body` produces the overall value once the
+/// iterator is exhausted, so it must be type-compatible with `$else`.
+#[macro_export]
+macro_rules! for_else {
+    ($pat:pat in ($iter:expr) $body:block else $else:block) => {{
+        let mut iter = ::std::iter::IntoIterator::into_iter($iter);
+        match iter.next() {
+            None => $else,
+            Some(first) => {
+                let mut item = first;
+                loop {
+                    let $pat = item;
+                    let value = $body;
+                    match iter.next() {
+                        Some(next) => item = next,
+                        None => break value,
+                    }
+                }
+            }
+        }
+    }};
+}
+
+/// Sing the countdown into `out`, starting from `count` and handling
+/// singular/plural for an arbitrary `container`/`beverage` pair.
+pub fn sing(count: u32, container: &str, beverage: &str, out: &mut impl Write) -> std::io::Result<()> {
+    let plural = |n: u32| if n == 1 { "" } else { "s" };
+    for n in (1..=count).rev() {
+        writeln!(out, "{} {}{} of {} on the wall, {} {}{} of {}.", n, container, plural(n), beverage, n, container, plural(n), beverage)?;
+        if n - 1 > 0 {
+            writeln!(out, "Take one down and pass it around, {} {}{} of {} on the wall.\n", n-1, container, plural(n-1), beverage)?;
+        } else {
+            writeln!(out, "Take one down and pass it around, no more {}s of {} on the wall.\n", container, beverage)?;
+        }
+    }
+    writeln!(out, "No more {}s of {} on the wall, no more {}s of {}.", container, beverage, container, beverage)?;
+    writeln!(out, "Go to the store and buy some more, {} {}{} of {} on the wall.", count, container, plural(count), beverage)?;
+    Ok(())
+}
+
+/// Generate the full "99 bottles of beer" lyrics as a single `String`.
+pub fn ninety_nine_bottles() -> String {
+    let mut out = Vec::new();
+    sing(99, "bottle", "beer", &mut out).expect("writing to a Vec is infallible");
+    String::from_utf8(out).expect("lyrics are valid UTF-8")
+}
+
+/// A game of Craps driven by the classic come-out/point state machine.
+pub mod craps {
+    use rand::Rng;
+    use std::io::{self, BufRead, Write};
+
+    /// Which phase of a single bet the table is in.
+    enum GameState {
+        ComeOut,
+        PointRolls,
+        GameOver,
+    }
+
+    /// A player sitting at the table with a wallet and a single live bet.
+    pub struct Game {
+        state: GameState,
+        wallet: i64,
+        bet: i64,
+        point: u8,
+        quit: bool,
+    }
+
+    /// Roll two d6 and return their sum.
+    fn roll() -> u8 {
+        let mut rng = rand::thread_rng();
+        rng.gen_range(1..=6) + rng.gen_range(1..=6)
+    }
+
+    /// Read a positive wager from stdin, re-prompting on non-positive or
+    /// unparseable input. Returns `None` once stdin reaches EOF so the
+    /// caller can end the game instead of spinning on a closed pipe.
+    fn read_number(prompt: &str) -> Option<i64> {
+        let stdin = io::stdin();
+        loop {
+            print!("{}", prompt);
+            io::stdout().flush().ok();
+            let mut line = String::new();
+            if stdin.lock().read_line(&mut line).unwrap_or(0) == 0 {
+                return None;
+            }
+            match line.trim().parse::<i64>() {
+                Ok(n) if n > 0 => return Some(n),
+                _ => println!("please enter a positive whole number"),
+            }
+        }
+    }
+
+    impl Game {
+        /// Seat a player with an opening `wallet`.
+        pub fn new(wallet: i64) -> Self {
+            Game { state: GameState::ComeOut, wallet, bet: 0, point: 0, quit: false }
+        }
+
+        /// Whether the player still has money to wager.
+        pub fn is_broke(&self) -> bool {
+            self.wallet <= 0
+        }
+
+        /// Begin a fresh come-out phase after a bet has settled.
+        fn reset(&mut self) {
+            self.state = GameState::ComeOut;
+            self.bet = 0;
+            self.point = 0;
+        }
+
+        /// Advance the game by one roll, returning whether the current bet
+        /// is still live (i.e. the round has not yet settled).
+        pub fn tick(&mut self) -> bool {
+            match self.state {
+                GameState::ComeOut => {
+                    match read_number("bet: ") {
+                        Some(n) => self.bet = n.min(self.wallet),
+                        None => {
+                            self.quit = true;
+                            self.state = GameState::GameOver;
+                            return false;
+                        }
+                    }
+                    let total = roll();
+                    println!("come-out roll: {}", total);
+                    match total {
+                        7 | 11 => {
+                            self.wallet += self.bet;
+                            println!("natural — you win! wallet: {}", self.wallet);
+                            self.state = GameState::GameOver;
+                        }
+                        2 | 3 | 12 => {
+                            self.wallet -= self.bet;
+                            println!("craps — you lose. wallet: {}", self.wallet);
+                            self.state = GameState::GameOver;
+                        }
+                        point => {
+                            self.point = point;
+                            println!("point is {}", point);
+                            self.state = GameState::PointRolls;
+                        }
+                    }
+                }
+                GameState::PointRolls => {
+                    let total = roll();
+                    println!("roll: {}", total);
+                    if total == self.point {
+                        self.wallet += self.bet;
+                        println!("hit the point — you win! wallet: {}", self.wallet);
+                        self.state = GameState::GameOver;
+                    } else if total == 7 {
+                        self.wallet -= self.bet;
+                        println!("seven out — you lose. wallet: {}", self.wallet);
+                        self.state = GameState::GameOver;
+                    }
+                }
+                GameState::GameOver => return false,
+            }
+            !matches!(self.state, GameState::GameOver)
+        }
+    }
+
+    /// Play rounds of Craps until the player runs out of money or stdin
+    /// closes at the bet prompt.
+    pub fn play() {
+        let mut game = Game::new(100);
+        while !game.is_broke() && !game.quit {
+            // Roll out a single bet to settlement, then start the next round.
+            while game.tick() {}
+            game.reset();
+        }
+        if game.is_broke() {
+            println!("out of money — thanks for playing!");
+        } else {
+            println!("thanks for playing!");
+        }
+    }
+}
+
+/// Watch a path and re-render the lyrics, colorized, on every change.
+///
+/// Uses `notify` for filesystem events, `syntect` for syntax highlighting
+/// of the regenerated verses, and `console` for clearing and styling the
+/// terminal between redraws. Rapid bursts of events are debounced so a
+/// single edit triggers exactly one repaint.
+pub fn watch(path: &std::path::Path) -> notify::Result<()> {
+    use notify::{RecursiveMode, Watcher};
+    use std::sync::mpsc::channel;
+    use std::time::Duration;
+
+    let (tx, rx) = channel();
+    let mut watcher = notify::recommended_watcher(move |res| {
+        let _ = tx.send(res);
+    })?;
+    watcher.watch(path, RecursiveMode::NonRecursive)?;
+
+    let term = console::Term::stdout();
+    let ss = syntect::parsing::SyntaxSet::load_defaults_newlines();
+    let ts = syntect::highlighting::ThemeSet::load_defaults();
+    let theme = &ts.themes["base16-ocean.dark"];
+    let syntax = ss.find_syntax_by_extension("rs").unwrap_or_else(|| ss.find_syntax_plain_text());
+
+    let render = |term: &console::Term| {
+        term.clear_screen().ok();
+        let mut highlighter = syntect::easy::HighlightLines::new(syntax, theme);
+        for line in ninety_nine_bottles().lines() {
+            let ranges = highlighter.highlight_line(line, &ss).unwrap_or_default();
+            let escaped = syntect::util::as_24_bit_terminal_escaped(&ranges[..], false);
+            println!("{}", escaped);
+        }
+    };
+
+    render(&term);
+    loop {
+        // Block for the first event, then drain the burst to debounce.
+        match rx.recv() {
+            Ok(Ok(_)) => {}
+            Ok(Err(e)) => return Err(e),
+            Err(_) => return Ok(()),
+        }
+        while rx.recv_timeout(Duration::from_millis(200)).is_ok() {}
+        render(&term);
+    }
+}
+
+/// Run an HQ9+ program, returning its accumulated output.
+///
+/// `H` prints `Hello, World!`, `Q` prints the program's own source,
+/// `9` emits the 99-bottles lyrics, and `+` increments an internal
+/// accumulator that is otherwise unobservable. Any other character is a
+/// hard error rather than a panic.
+pub fn execute(code: &str) -> Result<String, String> {
+    let mut out = String::new();
+    let mut accumulator: u64 = 0;
+    for c in code.chars() {
+        match c {
+            'H' => out.push_str("Hello, World!\n"),
+            'Q' => out.push_str(code),
+            '9' => out.push_str(&ninety_nine_bottles()),
+            '+' => accumulator += 1,
+            other => return Err(format!("unknown opcode: {:?}", other)),
+        }
+    }
+    let _ = accumulator;
+    Ok(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bottles_start_and_end() {
+        let song = ninety_nine_bottles();
+        assert!(song.starts_with("99 bottles of beer on the wall, 99 bottles of beer."));
+        assert!(song.trim_end().ends_with("Go to the store and buy some more, 99 bottles of beer on the wall."));
+    }
+
+    #[test]
+    fn sing_is_configurable() {
+        let mut out = Vec::new();
+        sing(2, "can", "soda", &mut out).unwrap();
+        let lyrics = String::from_utf8(out).unwrap();
+        assert!(lyrics.starts_with("2 cans of soda on the wall, 2 cans of soda."));
+        assert!(lyrics.contains("1 can of soda on the wall"));
+        assert!(lyrics.trim_end().ends_with("Go to the store and buy some more, 2 cans of soda on the wall."));
+    }
+
+    #[test]
+    fn for_else_runs_body_when_populated() {
+        let mut sum = 0;
+        let result = for_else!(x in ([1, 2, 3].iter()) {
+            sum += x;
+            sum
+        } else {
+            -1
+        });
+        assert_eq!(sum, 6);
+        assert_eq!(result, 6);
+    }
+
+    #[test]
+    fn for_else_falls_through_when_empty() {
+        let empty: &[i32] = &[];
+        let result = for_else!(_x in (empty.iter()) {
+            0
+        } else {
+            -1
+        });
+        assert_eq!(result, -1);
+    }
+
+    #[test]
+    fn op_h_prints_hello() {
+        assert_eq!(execute("H").unwrap(), "Hello, World!\n");
+    }
+
+    #[test]
+    fn op_q_is_a_quine() {
+        assert_eq!(execute("HQ").unwrap(), "Hello, World!\nHQ");
+    }
+
+    #[test]
+    fn op_9_emits_the_song() {
+        assert_eq!(execute("9").unwrap(), ninety_nine_bottles());
+    }
+
+    #[test]
+    fn op_plus_is_silent() {
+        assert_eq!(execute("+").unwrap(), "");
+    }
+
+    #[test]
+    fn unknown_opcode_errors() {
+        assert!(execute("Z").is_err());
+    }
+}
@@ -0,0 +1,40 @@
+use macros::{craps, execute, sing, watch};
+
+fn main() {
+    let mut start = 99u32;
+    let mut container = "bottle".to_string();
+    let mut beverage = "beer".to_string();
+    let mut args = std::env::args().skip(1).peekable();
+    if args.peek().map(String::as_str) == Some("craps") {
+        craps::play();
+        return;
+    }
+    // A bare first argument is taken as an HQ9+ program to execute; the
+    // song flags below are only consulted when no such program is given.
+    if let Some(program) = args.peek().filter(|a| !a.starts_with("--")).cloned() {
+        match execute(&program) {
+            Ok(out) => print!("{}", out),
+            Err(e) => eprintln!("error: {}", e),
+        }
+        return;
+    }
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--start" => if let Some(v) = args.next() { start = v.parse().unwrap_or(start); },
+            "--container" => if let Some(v) = args.next() { container = v; },
+            "--beverage" => if let Some(v) = args.next() { beverage = v; },
+            "--watch" => {
+                let path = args.next().unwrap_or_else(|| "src/lib.rs".to_string());
+                if let Err(e) = watch(std::path::Path::new(&path)) {
+                    eprintln!("error: {}", e);
+                }
+                return;
+            }
+            other => eprintln!("error: unknown argument: {}", other),
+        }
+    }
+    let stdout = std::io::stdout();
+    if let Err(e) = sing(start, &container, &beverage, &mut stdout.lock()) {
+        eprintln!("error: {}", e);
+    }
+}